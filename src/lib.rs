@@ -3,4 +3,24 @@
 //! > A child of the [AvdanOS](https://github.com/Avdan-OS) project.
 //! 
 //! A parser for [JSON5](https://json5.org/).
-//! 
\ No newline at end of file
+//!
+//! ## Features
+//! * `std` *(default)* &mdash; links the standard library.
+//!   Without it, the crate is `#![no_std]` and uses only `alloc`.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(all(test, not(feature = "std")))]
+mod tests {
+    use crate::alloc::{string::String, vec::Vec};
+
+    #[test]
+    fn no_std_alloc_smoke() {
+        let mut chars: Vec<char> = Vec::new();
+        chars.extend("{ a: 1 }".chars());
+
+        let text: String = chars.iter().collect();
+        assert_eq!(text, "{ a: 1 }");
+    }
+}